@@ -0,0 +1,45 @@
+use std::time::Instant;
+
+use crate::PlotApp;
+
+/// Summary timing statistics from a headless benchmark run.
+#[derive(Debug)]
+pub(crate) struct BenchmarkStats {
+    pub(crate) frame_count: usize,
+    pub(crate) total_ms: f64,
+    pub(crate) min_frame_ms: f64,
+    pub(crate) avg_frame_ms: f64,
+    pub(crate) max_frame_ms: f64,
+}
+
+/// Drives `app` through every `RunRace` record as fast as possible via
+/// `PlotApp::step_headless`, which does the same per-frame update,
+/// interpolation, blending, and replay-logging work the real draw loop does
+/// (minus painting to an eframe window), so the simulation can be
+/// regression-tested and profiled in CI without a display.
+pub(crate) fn run(app: &mut PlotApp) -> BenchmarkStats {
+    let mut frame_times_ms = Vec::new();
+
+    while !app.is_finished() {
+        let frame_start = Instant::now();
+        app.step_headless();
+        frame_times_ms.push(frame_start.elapsed().as_secs_f64() * 1000.0);
+    }
+
+    let total_ms: f64 = frame_times_ms.iter().sum();
+    let min_frame_ms = frame_times_ms.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max_frame_ms = frame_times_ms.iter().cloned().fold(0.0, f64::max);
+    let avg_frame_ms = if frame_times_ms.is_empty() {
+        0.0
+    } else {
+        total_ms / frame_times_ms.len() as f64
+    };
+
+    BenchmarkStats {
+        frame_count: frame_times_ms.len(),
+        total_ms,
+        min_frame_ms: if min_frame_ms.is_finite() { min_frame_ms } else { 0.0 },
+        avg_frame_ms,
+        max_frame_ms,
+    }
+}
@@ -9,19 +9,49 @@ use std::time::{Duration, Instant};
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
+mod benchmark;
+mod replay_log;
+
 #[derive(Debug, Deserialize)]
-struct LedCoordinate {
-    x_led: f64,
-    y_led: f64,
+pub(crate) struct LedCoordinate {
+    pub(crate) x_led: f64,
+    pub(crate) y_led: f64,
 }
 
 #[derive(Debug, Deserialize)]
-struct RunRace {
+pub(crate) struct RunRace {
     date: DateTime<Utc>,
     driver_number: u32,
+    pub(crate) x_led: f64,
+    pub(crate) y_led: f64,
+    time_delta: u64,
+}
+
+/// A driver's position at a single point on the simulated playback clock.
+#[derive(Debug, Clone, Copy)]
+struct DriverSample {
     x_led: f64,
     y_led: f64,
-    time_delta: u64,
+    // The record's own timestamp, as opposed to `time` below (its position
+    // on the simulated playback clock) - used to derive real elapsed time
+    // between a driver's own consecutive samples.
+    date: DateTime<Utc>,
+    time: DateTime<Utc>,
+}
+
+/// A driver's standing in the race: how far along the log it has consumed,
+/// and the timestamp of its most recently consumed waypoint.
+#[derive(Debug, Clone, Copy)]
+struct DriverProgress {
+    consumed_count: usize,
+    last_date: DateTime<Utc>,
+}
+
+/// A stop on a value-to-color gradient, e.g. speed-to-heatmap-color.
+#[derive(Debug, Clone, Copy)]
+struct Gradient {
+    value: f64,
+    color: egui::Color32,
 }
 
 struct PlotApp {
@@ -33,10 +63,49 @@ struct PlotApp {
     colors: HashMap<u32, egui::Color32>,
     current_index: usize,
     next_update_time: DateTime<Utc>,
+    // Cumulative sum of `time_delta` up to and including each index, so
+    // looking up the playback time of any index is O(1) instead of O(n).
+    prefix_time_deltas: Vec<u64>,
+    // Playback speed multiplier applied to the wall clock (0.25x-8x).
+    playback_speed: f32,
+    // Bracketing samples per driver, used to interpolate smooth motion between
+    // the last consumed `RunRace` record and the upcoming one.
+    driver_prev: HashMap<u32, DriverSample>,
+    driver_next: HashMap<u32, DriverSample>,
+    // Indices into `run_race_data` for each driver, in file order. Computed
+    // once since `run_race_data` never changes.
+    driver_rows: HashMap<u32, Vec<usize>>,
+    // How many of each driver's own rows have been consumed so far, i.e. an
+    // index into `driver_rows[driver]`. Lets a single-step advance update
+    // just the driver whose row was just consumed in O(1), instead of
+    // rescanning every driver's whole history.
+    driver_cursor: HashMap<u32, usize>,
+    // Running order state for the timing tower, keyed by driver_number.
+    driver_progress: HashMap<u32, DriverProgress>,
+    replay_logger: replay_log::ReplayLogger,
+    // Instantaneous speed of each driver's latest consumed segment, in LED
+    // coordinate units per millisecond.
+    driver_speed: HashMap<u32, f64>,
+    speed_gradient: Vec<Gradient>,
+    color_by_speed: bool,
 }
 
 impl PlotApp {
     fn new(coordinates: Vec<LedCoordinate>, run_race_data: Vec<RunRace>, colors: HashMap<u32, egui::Color32>) -> Self {
+        let mut cumulative = 0u64;
+        let prefix_time_deltas: Vec<u64> = run_race_data
+            .iter()
+            .map(|data| {
+                cumulative += data.time_delta;
+                cumulative
+            })
+            .collect();
+
+        let mut driver_rows: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (i, data) in run_race_data.iter().enumerate() {
+            driver_rows.entry(data.driver_number).or_default().push(i);
+        }
+
         let mut app = Self {
             coordinates,
             run_race_data,
@@ -46,37 +115,175 @@ impl PlotApp {
             colors,
             current_index: 0,
             next_update_time: Utc::now(),
+            prefix_time_deltas,
+            playback_speed: 1.0,
+            driver_prev: HashMap::new(),
+            driver_next: HashMap::new(),
+            driver_rows,
+            driver_cursor: HashMap::new(),
+            driver_progress: HashMap::new(),
+            replay_logger: replay_log::ReplayLogger::new(),
+            driver_speed: HashMap::new(),
+            speed_gradient: vec![
+                Gradient { value: 0.0, color: egui::Color32::from_rgb(0, 0, 255) },
+                Gradient { value: 0.02, color: egui::Color32::from_rgb(0, 255, 0) },
+                Gradient { value: 0.05, color: egui::Color32::from_rgb(255, 255, 0) },
+                Gradient { value: 0.1, color: egui::Color32::from_rgb(255, 0, 0) },
+            ],
+            color_by_speed: false,
         };
         app.calculate_next_update_time(); // Calculate initial next_update_time
+        app.rebuild_driver_state();
         app
     }
 
     fn reset(&mut self) {
+        if let Err(err) = self.replay_logger.write_csv("replay_log.csv") {
+            eprintln!("Error writing replay log: {err}");
+        }
+        self.replay_logger.reset();
         self.start_time = Instant::now();
         self.start_datetime = Utc::now();
         self.race_started = false;
         self.current_index = 0;
         self.calculate_next_update_time(); // Calculate next_update_time after reset
+        self.rebuild_driver_state();
     }
 
     fn calculate_next_update_time(&mut self) {
-        if let Some(run_data) = self.run_race_data.get(self.current_index) {
-            let mut total_time_delta = 0;
-            for data in self.run_race_data.iter().take(self.current_index + 1) {
-                total_time_delta += data.time_delta;
+        if let Some(&cumulative_time_delta) = self.prefix_time_deltas.get(self.current_index) {
+            self.next_update_time = self.start_datetime + Duration::from_millis(cumulative_time_delta);
+        }
+    }
+
+    /// Total elapsed simulated time since the start of the log, in milliseconds.
+    fn total_duration_ms(&self) -> u64 {
+        self.prefix_time_deltas.last().copied().unwrap_or(0)
+    }
+
+    /// Jumps playback to an arbitrary point on the timeline. The target index
+    /// is found in O(log n) via binary search over the precomputed prefix
+    /// sums, instead of replaying every record from the start; the per-driver
+    /// state is then rebuilt by `rebuild_driver_state`, which is O(n).
+    fn seek_to_offset_ms(&mut self, offset_ms: u64) {
+        let offset_ms = offset_ms.min(self.total_duration_ms());
+        self.current_index = self.prefix_time_deltas.partition_point(|&cumulative| cumulative < offset_ms);
+        self.start_datetime = Utc::now() - Duration::from_millis(offset_ms);
+        self.start_time = Instant::now() - Duration::from_millis((offset_ms as f64 / self.playback_speed as f64) as u64);
+        self.calculate_next_update_time();
+        self.rebuild_driver_state();
+    }
+
+    /// The current point on the simulated race clock, factoring in the
+    /// playback-speed multiplier applied to the wall clock.
+    fn simulated_now(&self) -> DateTime<Utc> {
+        let scaled_elapsed_ms = self.start_time.elapsed().as_secs_f64() * self.playback_speed as f64 * 1000.0;
+        self.start_datetime + Duration::from_millis(scaled_elapsed_ms as u64)
+    }
+
+    fn sample_at(&self, row_index: usize) -> DriverSample {
+        let data = &self.run_race_data[row_index];
+        DriverSample {
+            x_led: data.x_led,
+            y_led: data.y_led,
+            date: data.date,
+            time: self.start_datetime + Duration::from_millis(self.prefix_time_deltas[row_index]),
+        }
+    }
+
+    fn speed_between(prev: &DriverSample, next: &DriverSample) -> f64 {
+        let distance = ((next.x_led - prev.x_led).powi(2) + (next.y_led - prev.y_led).powi(2)).sqrt();
+        let elapsed_ms = (next.date - prev.date).num_milliseconds();
+        if elapsed_ms > 0 {
+            distance / elapsed_ms as f64
+        } else {
+            0.0
+        }
+    }
+
+    /// Rebuilds every driver's bracketing samples (`driver_prev` /
+    /// `driver_next`) and progress from scratch, for "jump" operations
+    /// (start, reset, seek) where `current_index` can move by more than one
+    /// step. O(n) here is fine since it happens once per jump, not per frame
+    /// - single-step ticks use the O(1) `advance_driver_state` instead.
+    fn rebuild_driver_state(&mut self) {
+        self.driver_prev.clear();
+        self.driver_next.clear();
+        self.driver_progress.clear();
+        self.driver_speed.clear();
+        self.driver_cursor.clear();
+
+        let driver_numbers: Vec<u32> = self.driver_rows.keys().copied().collect();
+        for driver_number in driver_numbers {
+            let rows = self.driver_rows[&driver_number].clone();
+            let consumed = rows.partition_point(|&row_index| row_index < self.current_index);
+            self.driver_cursor.insert(driver_number, consumed);
+
+            if consumed > 0 {
+                let prev = self.sample_at(rows[consumed - 1]);
+                self.driver_progress.insert(driver_number, DriverProgress {
+                    consumed_count: consumed,
+                    last_date: prev.date,
+                });
+                if consumed > 1 {
+                    let before = self.sample_at(rows[consumed - 2]);
+                    self.driver_speed.insert(driver_number, Self::speed_between(&before, &prev));
+                }
+                self.driver_prev.insert(driver_number, prev);
+            }
+
+            if let Some(&next_row) = rows.get(consumed) {
+                self.driver_next.insert(driver_number, self.sample_at(next_row));
+            }
+        }
+    }
+
+    /// Incrementally advances the state of the single driver whose row was
+    /// just consumed, in O(1) - instead of rescanning every driver's whole
+    /// history on every tick (which is O(n) per tick, O(n^2) over a full
+    /// replay).
+    fn advance_driver_state(&mut self, consumed_index: usize) {
+        let driver_number = self.run_race_data[consumed_index].driver_number;
+        let consumed = self.sample_at(consumed_index);
+
+        if let Some(prev) = self.driver_prev.insert(driver_number, consumed) {
+            self.driver_speed.insert(driver_number, Self::speed_between(&prev, &consumed));
+        }
+
+        let progress = self.driver_progress.entry(driver_number).or_insert(DriverProgress {
+            consumed_count: 0,
+            last_date: consumed.date,
+        });
+        progress.consumed_count += 1;
+        progress.last_date = consumed.date;
+
+        let cursor_entry = self.driver_cursor.entry(driver_number).or_insert(0);
+        *cursor_entry += 1;
+        let cursor = *cursor_entry;
+
+        match self.driver_rows[&driver_number].get(cursor).copied() {
+            Some(next_row) => {
+                let next_sample = self.sample_at(next_row);
+                self.driver_next.insert(driver_number, next_sample);
+            }
+            None => {
+                self.driver_next.remove(&driver_number);
             }
-            self.next_update_time = self.start_datetime + Duration::from_millis(total_time_delta);
         }
     }
 
     fn update_race(&mut self) {
         if self.race_started {
-            let current_time = Utc::now();
+            let current_time = self.simulated_now();
 
             if current_time >= self.next_update_time {
-                self.current_index += 1;
-                if self.current_index < self.run_race_data.len() {
-                    self.calculate_next_update_time(); // Calculate next update time for the next data point
+                let consumed_index = self.current_index;
+                if consumed_index < self.run_race_data.len() {
+                    self.current_index += 1;
+                    if self.current_index < self.run_race_data.len() {
+                        self.calculate_next_update_time(); // Calculate next update time for the next data point
+                    }
+                    self.advance_driver_state(consumed_index);
                 }
             }
         }
@@ -85,6 +292,228 @@ impl PlotApp {
     fn scale_f64(value: f64, scale: i64) -> i64 {
         (value * scale as f64) as i64
     }
+
+    /// Every LED any driver has touched up to `current_index`, grouped so
+    /// overlapping waypoints can be blended before drawing.
+    fn build_trail_led_colors(&self) -> HashMap<(i64, i64), Vec<egui::Color32>> {
+        let mut led_colors: HashMap<(i64, i64), Vec<egui::Color32>> = HashMap::new();
+        let scale_factor = 1_000_000;
+
+        for run_data in self.run_race_data.iter().take(self.current_index) {
+            let color = self.colors.get(&run_data.driver_number).copied().unwrap_or(egui::Color32::WHITE);
+            let coord_key = (
+                Self::scale_f64(run_data.x_led, scale_factor),
+                Self::scale_f64(run_data.y_led, scale_factor),
+            );
+            led_colors.entry(coord_key).or_default().push(color);
+        }
+
+        led_colors
+    }
+
+    /// Each active driver's smoothly interpolated current position (nearest
+    /// LED, color), for layering on top of the trail.
+    fn current_driver_colors(&self, now: DateTime<Utc>) -> Vec<(f64, f64, egui::Color32)> {
+        self.driver_prev
+            .iter()
+            .map(|(driver_number, prev)| {
+                let color = if self.color_by_speed {
+                    let speed = self.driver_speed.get(driver_number).copied().unwrap_or(0.0);
+                    Self::sample_gradient(&self.speed_gradient, speed)
+                } else {
+                    self.colors.get(driver_number).copied().unwrap_or(egui::Color32::WHITE)
+                };
+                let (x, y) = Self::interpolated_position(prev, self.driver_next.get(driver_number), now);
+                let nearest = Self::nearest_led(&self.coordinates, x, y);
+                (nearest.x_led, nearest.y_led, color)
+            })
+            .collect()
+    }
+
+    /// Whether every `RunRace` record has been consumed.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.current_index >= self.run_race_data.len()
+    }
+
+    /// Advances exactly one record and performs the same per-frame work the
+    /// real draw loop does - building the LED trail, blending overlapping
+    /// waypoints, interpolating each active driver's current position, and
+    /// replay logging - minus anything that needs an eframe window. Returns
+    /// the number of distinct lit LEDs, mirroring what a real frame would
+    /// show, so a headless benchmark measures the actual per-frame cost
+    /// instead of one helper in isolation.
+    pub(crate) fn step_headless(&mut self) -> usize {
+        let consumed_index = self.current_index;
+        self.current_index += 1;
+        self.advance_driver_state(consumed_index);
+
+        // Headless runs aren't paced to real time, so `simulated_now()`
+        // (wall-clock based) would stay pinned near `start_datetime`
+        // regardless of how far `current_index` has advanced. Derive "now"
+        // from the record just consumed instead, the same way `sample_at`
+        // does, so per-frame stats stay meaningful.
+        let sim_time_ms = self.prefix_time_deltas.get(consumed_index).copied().unwrap_or(0);
+        let now = self.start_datetime + Duration::from_millis(sim_time_ms);
+
+        let led_colors = self.build_trail_led_colors();
+        for colors in led_colors.values() {
+            Self::blend_colors(colors);
+        }
+        let _ = self.current_driver_colors(now);
+
+        let active_leds = led_colors.len();
+        self.replay_logger.record(self.current_index, sim_time_ms, active_leds);
+
+        active_leds
+    }
+
+    /// Converts an sRGB channel (0.0-1.0) to linear light.
+    fn srgb_to_linear(c: f64) -> f64 {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    /// Converts a linear-light channel (0.0-1.0) back to sRGB.
+    fn linear_to_srgb(c: f64) -> f64 {
+        if c <= 0.0031308 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    /// Averages overlapping drivers' colors in linear light and converts the
+    /// result back to sRGB, so two team colors sharing an LED blend cleanly
+    /// instead of muddying like a raw average of their sRGB bytes would.
+    fn blend_colors(colors: &[egui::Color32]) -> egui::Color32 {
+        if colors.len() == 1 {
+            return colors[0];
+        }
+
+        let mut linear = [0.0f64; 3];
+        for color in colors {
+            linear[0] += Self::srgb_to_linear(color.r() as f64 / 255.0);
+            linear[1] += Self::srgb_to_linear(color.g() as f64 / 255.0);
+            linear[2] += Self::srgb_to_linear(color.b() as f64 / 255.0);
+        }
+
+        let n = colors.len() as f64;
+        let to_byte = |c: f64| (Self::linear_to_srgb(c / n) * 255.0).round() as u8;
+        egui::Color32::from_rgb(to_byte(linear[0]), to_byte(linear[1]), to_byte(linear[2]))
+    }
+
+    /// Finds the LED grid coordinate closest to an arbitrary (interpolated)
+    /// point, so free-floating positions still land on a paintable LED.
+    pub(crate) fn nearest_led(coordinates: &[LedCoordinate], x: f64, y: f64) -> &LedCoordinate {
+        coordinates
+            .iter()
+            .min_by(|a, b| {
+                let dist_a = (a.x_led - x).powi(2) + (a.y_led - y).powi(2);
+                let dist_b = (b.x_led - x).powi(2) + (b.y_led - y).powi(2);
+                dist_a.partial_cmp(&dist_b).unwrap()
+            })
+            .expect("coordinates should not be empty")
+    }
+
+    /// Samples a value-to-color gradient: finds the first stop whose
+    /// threshold exceeds `value`, then interpolates between it and the
+    /// previous stop, clamping to the first/last stop outside the range.
+    fn sample_gradient(gradient: &[Gradient], value: f64) -> egui::Color32 {
+        let first = gradient.first().expect("gradient must have at least one stop");
+        if value <= first.value {
+            return first.color;
+        }
+
+        for pair in gradient.windows(2) {
+            let (left, right) = (pair[0], pair[1]);
+            if value <= right.value {
+                let a = ((value - left.value) / (right.value - left.value)).clamp(0.0, 1.0);
+                return Self::lerp_color(left.color, right.color, a);
+            }
+        }
+
+        gradient.last().expect("gradient must have at least one stop").color
+    }
+
+    fn lerp_color(left: egui::Color32, right: egui::Color32, a: f64) -> egui::Color32 {
+        let lerp_channel = |l: u8, r: u8| (l as f64 * (1.0 - a) + r as f64 * a).round() as u8;
+        egui::Color32::from_rgb(
+            lerp_channel(left.r(), right.r()),
+            lerp_channel(left.g(), right.g()),
+            lerp_channel(left.b(), right.b()),
+        )
+    }
+
+    /// Linearly interpolated position of a driver between its bracketing
+    /// samples, at the current moment on the simulated playback clock.
+    fn interpolated_position(prev: &DriverSample, next: Option<&DriverSample>, now: DateTime<Utc>) -> (f64, f64) {
+        let Some(next) = next else {
+            return (prev.x_led, prev.y_led);
+        };
+
+        let span = (next.time - prev.time).num_milliseconds() as f64;
+        let a = if span > 0.0 {
+            ((now - prev.time).num_milliseconds() as f64 / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        (
+            prev.x_led * (1.0 - a) + next.x_led * a,
+            prev.y_led * (1.0 - a) + next.y_led * a,
+        )
+    }
+
+    /// Formats a non-negative time gap as `+M:SS.mmm`.
+    fn format_gap(leader_date: DateTime<Utc>, driver_date: DateTime<Utc>) -> String {
+        let total_millis = (leader_date - driver_date).num_milliseconds().max(0);
+        let minutes = total_millis / 60_000;
+        let seconds = (total_millis % 60_000) / 1_000;
+        let millis = total_millis % 1_000;
+        format!("+{}:{:02}.{:03}", minutes, seconds, millis)
+    }
+
+    /// Draws the timing-tower side panel: drivers sorted by running order
+    /// with their gap to the leader.
+    fn draw_timing_tower(&self, ctx: &egui::Context) {
+        egui::SidePanel::right("timing_tower").show(ctx, |ui| {
+            ui.heading("Timing Tower");
+            ui.separator();
+
+            let mut standings: Vec<(u32, DriverProgress)> =
+                self.driver_progress.iter().map(|(&driver_number, &progress)| (driver_number, progress)).collect();
+            standings.sort_by(|a, b| {
+                // At equal progress, the driver who reached it sooner (a
+                // smaller `last_date`) is the one actually ahead.
+                b.1.consumed_count
+                    .cmp(&a.1.consumed_count)
+                    .then_with(|| a.1.last_date.cmp(&b.1.last_date))
+            });
+
+            let leader_date = standings.first().map(|(_, progress)| progress.last_date);
+
+            for (position, (driver_number, progress)) in standings.iter().enumerate() {
+                let color = self.colors.get(driver_number).copied().unwrap_or(egui::Color32::WHITE);
+                let gap = if position == 0 {
+                    "LEADER".to_string()
+                } else {
+                    match leader_date {
+                        Some(leader_date) => Self::format_gap(leader_date, progress.last_date),
+                        None => "+0:00.000".to_string(),
+                    }
+                };
+
+                ui.horizontal(|ui| {
+                    ui.colored_label(color, format!("P{}", position + 1));
+                    ui.label(format!("#{driver_number}"));
+                    ui.label(gap);
+                });
+            }
+        });
+    }
 }
 
 impl App for PlotApp {
@@ -118,26 +547,49 @@ impl App for PlotApp {
                     self.start_datetime = Utc::now();
                     self.current_index = 0;
                     self.calculate_next_update_time(); // Calculate next update time when race starts
+                    self.rebuild_driver_state();
                 }
                 if ui.button("STOP").clicked() {
                     self.reset();
                 }
+
+                ui.separator();
+                ui.add(egui::Slider::new(&mut self.playback_speed, 0.25..=8.0).text("Speed"));
+
+                ui.separator();
+                ui.checkbox(&mut self.color_by_speed, "Color by speed");
+            });
+
+            ui.horizontal(|ui| {
+                let total_ms = self.total_duration_ms();
+                let mut timeline_ms = self
+                    .current_index
+                    .checked_sub(1)
+                    .and_then(|i| self.prefix_time_deltas.get(i))
+                    .copied()
+                    .unwrap_or(0);
+
+                let response = ui.add(egui::Slider::new(&mut timeline_ms, 0..=total_ms).text("Timeline (ms)"));
+                if response.changed() {
+                    self.seek_to_offset_ms(timeline_ms);
+                }
             });
         });
 
+        self.draw_timing_tower(ctx);
+
         egui::CentralPanel::default().show(ctx, |ui| {
-            let mut led_colors: HashMap<(i64, i64), egui::Color32> = HashMap::new();
             let scale_factor = 1_000_000;
+            let now = self.simulated_now();
 
-            for run_data in self.run_race_data.iter().take(self.current_index) {
-                let color = self.colors.get(&run_data.driver_number).copied().unwrap_or(egui::Color32::WHITE);
+            // Full trail: every LED any driver has touched up to
+            // `current_index`, blended where multiple drivers' waypoints
+            // land on the same LED.
+            let led_colors = self.build_trail_led_colors();
 
-                let coord_key = (
-                    Self::scale_f64(run_data.x_led, scale_factor),
-                    Self::scale_f64(run_data.y_led, scale_factor),
-                );
-
-                led_colors.insert(coord_key, color);
+            if self.race_started {
+                let sim_time_ms = (now - self.start_datetime).num_milliseconds().max(0) as u64;
+                self.replay_logger.record(self.current_index, sim_time_ms, led_colors.len());
             }
 
             for coord in &self.coordinates {
@@ -154,10 +606,27 @@ impl App for PlotApp {
                 );
             }
 
-            for ((x, y), color) in led_colors {
+            for ((x, y), colors) in led_colors {
                 let norm_x = ((x as f64 / scale_factor as f64 - min_x) / width) as f32 * ui.available_width();
                 let norm_y = ui.available_height() - (((y as f64 / scale_factor as f64 - min_y) / height) as f32 * ui.available_height());
 
+                painter.rect_filled(
+                    egui::Rect::from_min_size(
+                        egui::pos2(norm_x, norm_y),
+                        egui::vec2(20.0, 20.0),
+                    ),
+                    egui::Rounding::same(0.0),
+                    Self::blend_colors(&colors),
+                );
+            }
+
+            // Layer each active driver's smoothly interpolated current
+            // position on top of the trail, so motion reads as continuous
+            // instead of snapping between logged waypoints.
+            for (x_led, y_led, color) in self.current_driver_colors(now) {
+                let norm_x = ((x_led - min_x) / width) as f32 * ui.available_width();
+                let norm_y = ui.available_height() - (((y_led - min_y) / height) as f32 * ui.available_height());
+
                 painter.rect_filled(
                     egui::Rect::from_min_size(
                         egui::pos2(norm_x, norm_y),
@@ -201,6 +670,17 @@ fn main() -> eframe::Result<()> {
     colors.insert(77, egui::Color32::from_rgb(165, 160, 155)); // Valtteri Bottas, Stake F1
     colors.insert(81, egui::Color32::from_rgb(255, 135, 0));   // Oscar Piastri, McLaren
 
+    if std::env::args().any(|arg| arg == "--benchmark") {
+        let mut app = PlotApp::new(coordinates, run_race_data, colors);
+        let stats = benchmark::run(&mut app);
+        println!("frames:    {}", stats.frame_count);
+        println!("total:     {:.3} ms", stats.total_ms);
+        println!("min frame: {:.3} ms", stats.min_frame_ms);
+        println!("avg frame: {:.3} ms", stats.avg_frame_ms);
+        println!("max frame: {:.3} ms", stats.max_frame_ms);
+        return Ok(());
+    }
+
     let app = PlotApp::new(coordinates, run_race_data, colors);
 
     let native_options = eframe::NativeOptions::default();
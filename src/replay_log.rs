@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
+
+/// Per-frame statistics captured while a replay plays, for later analysis or
+/// regression comparisons against prior runs.
+#[derive(Debug, Clone)]
+pub(crate) struct FrameStat {
+    wall_clock: DateTime<Utc>,
+    current_index: usize,
+    sim_time_ms: u64,
+    active_leds: usize,
+    frame_interval_ms: f64,
+}
+
+/// Accumulates `FrameStat`s for the duration of a replay and flushes them to
+/// CSV when the replay stops.
+#[derive(Default)]
+pub(crate) struct ReplayLogger {
+    frames: Vec<FrameStat>,
+    last_frame_at: Option<std::time::Instant>,
+}
+
+impl ReplayLogger {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears accumulated frames so a fresh replay starts from an empty log.
+    pub(crate) fn reset(&mut self) {
+        self.frames.clear();
+        self.last_frame_at = None;
+    }
+
+    pub(crate) fn record(&mut self, current_index: usize, sim_time_ms: u64, active_leds: usize) {
+        let now = std::time::Instant::now();
+        let frame_interval_ms = match self.last_frame_at {
+            Some(prev) => now.duration_since(prev).as_secs_f64() * 1000.0,
+            None => 0.0,
+        };
+        self.last_frame_at = Some(now);
+
+        self.frames.push(FrameStat {
+            wall_clock: Utc::now(),
+            current_index,
+            sim_time_ms,
+            active_leds,
+            frame_interval_ms,
+        });
+    }
+
+    /// Writes the accumulated frames to a CSV file, one row per frame.
+    pub(crate) fn write_csv(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        let mut wtr = csv::Writer::from_path(file_path)?;
+        wtr.write_record(["wall_clock", "current_index", "sim_time_ms", "active_leds", "frame_interval_ms"])?;
+        for frame in &self.frames {
+            wtr.write_record(&[
+                frame.wall_clock.to_rfc3339(),
+                frame.current_index.to_string(),
+                frame.sim_time_ms.to_string(),
+                frame.active_leds.to_string(),
+                format!("{:.3}", frame.frame_interval_ms),
+            ])?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+}